@@ -0,0 +1,56 @@
+//! Cross-platform "copy this capture to the system clipboard", used by
+//! `copy_image_to_clipboard`. Mirrors `open_with`'s pattern of a thin public
+//! entrypoint over a per-platform `platform` submodule.
+
+use std::path::Path;
+
+pub(crate) fn copy_image(path: &Path) -> Result<(), String> {
+    platform::copy_image(path)
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::path::Path;
+    use std::process::Command;
+
+    pub(crate) fn copy_image(path: &Path) -> Result<(), String> {
+        let output = Command::new("osascript")
+            .arg("-e")
+            .arg(format!(
+                "set the clipboard to (read file POSIX file \"{}\") as JPEG picture",
+                path.to_string_lossy()
+            ))
+            .output()
+            .map_err(|e| format!("Failed to execute osascript: {}", e))?;
+
+        if !output.status.success() {
+            let error_msg = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Failed to copy image to clipboard: {}", error_msg));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+mod platform {
+    use std::path::Path;
+
+    pub(crate) fn copy_image(path: &Path) -> Result<(), String> {
+        let img = image::open(path)
+            .map_err(|e| format!("Failed to decode image: {}", e))?
+            .to_rgba8();
+        let (width, height) = img.dimensions();
+
+        let mut clipboard = arboard::Clipboard::new()
+            .map_err(|e| format!("Failed to access clipboard: {}", e))?;
+
+        clipboard
+            .set_image(arboard::ImageData {
+                width: width as usize,
+                height: height as usize,
+                bytes: img.into_raw().into(),
+            })
+            .map_err(|e| format!("Failed to copy image to clipboard: {}", e))
+    }
+}
@@ -8,9 +8,16 @@ use std::path::PathBuf;
 use tauri::{Manager, WindowEvent};
 use serde::{Deserialize, Serialize};
 use base64::engine::Engine;
+use image::GenericImageView;
 
-#[derive(Debug, Serialize, Deserialize)]
-struct CaptureFile {
+mod clipboard;
+mod open_with;
+mod paths;
+mod thumbnails;
+mod watcher;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub(crate) struct CaptureFile {
     name: String,
     path: String,
     modified: u64,
@@ -20,7 +27,7 @@ struct CaptureFile {
     metadata: Option<CaptureMetadata>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 struct CaptureMetadata {
     id: String,
     timestamp: String,
@@ -32,9 +39,15 @@ struct CaptureMetadata {
     #[serde(rename = "fileSize")]
     file_size: i64,
     metadata: MetadataDetails,
+    /// Fields the real Grab writer may store alongside the ones above that
+    /// we don't otherwise model. Flattened so round-tripping a sidecar we
+    /// only partially understand (e.g. to enrich it with dimensions) doesn't
+    /// silently drop them.
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 struct MetadataDetails {
     dimensions: Option<Dimensions>,
     #[serde(rename = "applicationName")]
@@ -43,121 +56,368 @@ struct MetadataDetails {
     window_title: Option<String>,
     #[serde(rename = "clipboardType")]
     clipboard_type: Option<String>,
+    /// Perceptual average-hash, computed on read for images whose metadata
+    /// lacks dimensions (see `enrich_image_metadata`) so duplicate shots can
+    /// later be grouped by a "find duplicates" view.
+    #[serde(default)]
+    hash: Option<String>,
+    /// Same round-tripping concern as `CaptureMetadata::extra`, one level
+    /// down.
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 struct Dimensions {
     width: f64,
     height: f64,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Clone)]
 struct AppSettings {
-    capture_folder: String,
+    /// Every folder the gallery aggregates captures from. Order is
+    /// preserved so the first entry acts as the "primary" folder for
+    /// commands that only need a single location (see `paths::captures_dir`).
+    capture_folders: Vec<String>,
     default_capture_folder: String,
 }
 
-#[tauri::command]
-fn get_captures_dir() -> Result<String, String> {
-    // Try to get custom folder from settings, fallback to default
-    match get_app_settings_internal() {
-        Ok(settings) => {
-            let custom_path = PathBuf::from(&settings.capture_folder);
-            if custom_path.exists() {
-                Ok(settings.capture_folder)
-            } else {
-                // Fallback to default
-                let default_path = get_default_captures_dir()?;
-                Ok(default_path.to_string_lossy().to_string())
-            }
-        },
-        Err(_) => {
-            let default_path = get_default_captures_dir()?;
-            Ok(default_path.to_string_lossy().to_string())
+impl<'de> Deserialize<'de> for AppSettings {
+    /// Pre-multi-folder installs wrote a single `capture_folder: String`.
+    /// Deserialize through an intermediate that accepts either shape so
+    /// those settings.json files keep working instead of failing to parse
+    /// and silently reverting the user to the default captures dir.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawAppSettings {
+            #[serde(default)]
+            capture_folders: Option<Vec<String>>,
+            #[serde(default)]
+            capture_folder: Option<String>,
+            #[serde(default)]
+            default_capture_folder: Option<String>,
         }
+
+        let raw = RawAppSettings::deserialize(deserializer)?;
+
+        let capture_folders = raw
+            .capture_folders
+            .or_else(|| raw.capture_folder.clone().map(|folder| vec![folder]))
+            .unwrap_or_default();
+
+        let default_capture_folder = raw
+            .default_capture_folder
+            .or(raw.capture_folder)
+            .or_else(|| capture_folders.first().cloned())
+            .unwrap_or_default();
+
+        Ok(AppSettings { capture_folders, default_capture_folder })
     }
 }
 
 #[tauri::command]
-fn list_captures() -> Result<Vec<CaptureFile>, String> {
-    let home_dir = dirs::home_dir().ok_or("Could not find home directory")?;
-    let captures_path = home_dir.join("Library/Application Support/Grab/captures");
-    
-    if !captures_path.exists() {
-        return Ok(vec![]);
-    }
+pub(crate) fn get_captures_dir() -> Result<String, String> {
+    Ok(paths::captures_dir().to_string_lossy().to_string())
+}
 
+#[tauri::command]
+fn list_captures() -> Result<Vec<CaptureFile>, String> {
     let mut captures = Vec::new();
-    
-    let entries = fs::read_dir(&captures_path)
-        .map_err(|e| format!("Failed to read captures directory: {}", e))?;
-    
-    for entry in entries {
-        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
-        let path = entry.path();
-        
-        if path.is_file() {
-            if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                let is_image = name.to_lowercase().ends_with(".png") || 
-                              name.to_lowercase().ends_with(".jpg") || 
-                              name.to_lowercase().ends_with(".jpeg") ||
-                              name.to_lowercase().ends_with(".gif") ||
-                              name.to_lowercase().ends_with(".bmp") ||
-                              name.to_lowercase().ends_with(".webp");
-                let is_text = name.ends_with(".txt");
-                
-                if is_image || is_text {
-                    let file_metadata = entry.metadata()
-                        .map_err(|e| format!("Failed to read file metadata: {}", e))?;
-                    
-                    let modified = file_metadata
-                        .modified()
-                        .unwrap_or(std::time::UNIX_EPOCH)
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_secs();
-                    
-                    // Check for corresponding JSON metadata file
-                    let json_filename = format!("{}.json", name);
-                    let json_path = captures_path.join(&json_filename);
-                    let has_metadata = json_path.exists();
-                    
-                    // Try to load metadata if it exists
-                    let capture_metadata = if has_metadata {
-                        match load_capture_metadata(&json_path) {
-                            Ok(metadata) => Some(metadata),
-                            Err(_) => None, // Continue without metadata if parsing fails
-                        }
-                    } else {
-                        None
-                    };
-                    
-                    let capture_type = if is_image {
-                        "image".to_string()
-                    } else {
-                        "text".to_string()
-                    };
-                    
-                    captures.push(CaptureFile {
-                        name: name.to_string(),
-                        path: path.to_string_lossy().to_string(),
-                        modified,
-                        size: file_metadata.len(),
-                        capture_type,
-                        has_metadata,
-                        metadata: capture_metadata,
-                    });
+    let mut seen_paths = std::collections::HashSet::new();
+
+    for captures_path in paths::capture_folders() {
+        if !captures_path.exists() {
+            continue;
+        }
+
+        let entries = fs::read_dir(&captures_path)
+            .map_err(|e| format!("Failed to read captures directory: {}", e))?;
+
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            let path = entry.path();
+
+            if path.is_file() && seen_paths.insert(path.clone()) {
+                if let Some(capture) = build_capture_file(&path) {
+                    captures.push(enrich_image_metadata(capture));
                 }
             }
         }
     }
-    
+
     // Sort by modified time (newest first)
     captures.sort_by(|a, b| b.modified.cmp(&a.modified));
-    
+
     Ok(captures)
 }
 
+/// For image captures whose sidecar metadata is missing dimensions, decode
+/// the file to fill in width/height and a perceptual hash, cache a
+/// downscaled thumbnail, and write the derived fields back to the sidecar
+/// so this only happens once per capture.
+fn enrich_image_metadata(mut capture: CaptureFile) -> CaptureFile {
+    if capture.capture_type != "image" {
+        return capture;
+    }
+
+    // Captures saved without a sidecar JSON at all are the common case of
+    // "lacking dimensions", not just ones with an existing-but-incomplete
+    // sidecar, so both need enrichment.
+    let needs_enrichment = match &capture.metadata {
+        Some(metadata) => metadata.metadata.dimensions.is_none(),
+        None => true,
+    };
+
+    if !needs_enrichment {
+        return capture;
+    }
+
+    let img = match image::open(&capture.path) {
+        Ok(img) => img,
+        Err(e) => {
+            eprintln!("Failed to decode {} for metadata enrichment: {}", capture.name, e);
+            return capture;
+        }
+    };
+
+    if let Err(e) = thumbnails::ensure_cached(std::path::Path::new(&capture.path), &img) {
+        eprintln!("Failed to cache thumbnail for {}: {}", capture.name, e);
+    }
+
+    let dimensions = Dimensions { width: img.width() as f64, height: img.height() as f64 };
+    let hash = thumbnails::average_hash(&img);
+
+    let metadata = capture
+        .metadata
+        .get_or_insert_with(|| default_capture_metadata(&capture.name, &capture.capture_type, capture.size));
+    metadata.metadata.dimensions = Some(dimensions);
+    metadata.metadata.hash = Some(hash);
+
+    if let Err(e) = write_capture_metadata(std::path::Path::new(&capture.path), metadata) {
+        eprintln!("Failed to persist enriched metadata for {}: {}", capture.name, e);
+    } else {
+        capture.has_metadata = true;
+    }
+
+    capture
+}
+
+/// Minimal `CaptureMetadata` for an image that has no sidecar JSON at all,
+/// so enrichment has somewhere to attach dimensions/hash. Fields the sidecar
+/// would normally capture at save time (application name, window title,
+/// clipboard type) stay `None` since we have no way to recover them here.
+fn default_capture_metadata(name: &str, capture_type: &str, file_size: u64) -> CaptureMetadata {
+    let file_extension = std::path::Path::new(name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_string();
+
+    CaptureMetadata {
+        id: name.to_string(),
+        timestamp: String::new(),
+        capture_type: capture_type.to_string(),
+        filename: name.to_string(),
+        file_extension,
+        file_size: file_size as i64,
+        metadata: MetadataDetails {
+            dimensions: None,
+            application_name: None,
+            window_title: None,
+            clipboard_type: None,
+            hash: None,
+            extra: serde_json::Map::new(),
+        },
+        extra: serde_json::Map::new(),
+    }
+}
+
+fn write_capture_metadata(capture_path: &std::path::Path, metadata: &CaptureMetadata) -> Result<(), String> {
+    let name = capture_path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+    let json_path = capture_path.with_file_name(format!("{}.json", name));
+
+    let json = serde_json::to_string_pretty(metadata)
+        .map_err(|e| format!("Failed to serialize metadata: {}", e))?;
+
+    fs::write(json_path, json).map_err(|e| format!("Failed to write metadata: {}", e))
+}
+
+#[tauri::command]
+fn get_capture_thumbnail(filename: String, max_px: Option<u32>) -> Result<String, String> {
+    let file_path = paths::locate_capture(&filename)
+        .ok_or_else(|| format!("Image file not found: {}", filename))?;
+
+    let max_px = max_px.unwrap_or(thumbnails::DEFAULT_MAX_PX);
+    let thumb_path = thumbnails::thumbnail_path(&file_path);
+
+    if max_px == thumbnails::DEFAULT_MAX_PX && thumb_path.exists() {
+        let bytes = fs::read(&thumb_path)
+            .map_err(|e| format!("Failed to read cached thumbnail: {}", e))?;
+        return Ok(base64::engine::general_purpose::STANDARD.encode(&bytes));
+    }
+
+    let img = image::open(&file_path).map_err(|e| format!("Failed to decode image: {}", e))?;
+    let thumbnail = img.thumbnail(max_px, max_px);
+
+    let mut bytes: Vec<u8> = Vec::new();
+    thumbnail
+        .to_rgb8()
+        .write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Jpeg)
+        .map_err(|e| format!("Failed to encode thumbnail: {}", e))?;
+
+    Ok(base64::engine::general_purpose::STANDARD.encode(&bytes))
+}
+
+/// Filter criteria for `search_captures`. Every field is optional and
+/// fields present are AND-ed together; `text` matches the capture's filename
+/// and, for `.txt` captures, their contents (it is not checked against
+/// metadata fields).
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct SearchQuery {
+    text: Option<String>,
+    application_name: Option<String>,
+    window_title: Option<String>,
+    clipboard_type: Option<String>,
+    capture_type: Option<String>,
+    modified_after: Option<u64>,
+    modified_before: Option<u64>,
+}
+
+#[tauri::command]
+fn search_captures(query: SearchQuery) -> Result<Vec<CaptureFile>, String> {
+    let captures = list_captures()?;
+
+    Ok(captures
+        .into_iter()
+        .filter(|capture| matches_query(capture, &query))
+        .collect())
+}
+
+fn matches_query(capture: &CaptureFile, query: &SearchQuery) -> bool {
+    if let Some(capture_type) = &query.capture_type {
+        if &capture.capture_type != capture_type {
+            return false;
+        }
+    }
+
+    if let Some(after) = query.modified_after {
+        if capture.modified < after {
+            return false;
+        }
+    }
+
+    if let Some(before) = query.modified_before {
+        if capture.modified > before {
+            return false;
+        }
+    }
+
+    let details = capture.metadata.as_ref().map(|m| &m.metadata);
+
+    if let Some(application_name) = &query.application_name {
+        if !field_contains(details.and_then(|d| d.application_name.as_deref()), application_name) {
+            return false;
+        }
+    }
+
+    if let Some(window_title) = &query.window_title {
+        if !field_contains(details.and_then(|d| d.window_title.as_deref()), window_title) {
+            return false;
+        }
+    }
+
+    if let Some(clipboard_type) = &query.clipboard_type {
+        if !field_contains(details.and_then(|d| d.clipboard_type.as_deref()), clipboard_type) {
+            return false;
+        }
+    }
+
+    if let Some(text) = &query.text {
+        if !matches_text(capture, text) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn field_contains(field: Option<&str>, needle: &str) -> bool {
+    match field {
+        Some(value) => value.to_lowercase().contains(&needle.to_lowercase()),
+        None => false,
+    }
+}
+
+fn matches_text(capture: &CaptureFile, needle: &str) -> bool {
+    let needle = needle.to_lowercase();
+
+    if capture.name.to_lowercase().contains(&needle) {
+        return true;
+    }
+
+    if capture.capture_type == "text" {
+        if let Ok(content) = fs::read_to_string(&capture.path) {
+            if content.to_lowercase().contains(&needle) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Build a `CaptureFile` for a single path, loading its sidecar metadata if
+/// present. Shared by `list_captures` and the filesystem watcher so both
+/// agree on what counts as a capture.
+pub(crate) fn build_capture_file(path: &std::path::Path) -> Option<CaptureFile> {
+    let name = path.file_name().and_then(|n| n.to_str())?;
+
+    let is_image = name.to_lowercase().ends_with(".png")
+        || name.to_lowercase().ends_with(".jpg")
+        || name.to_lowercase().ends_with(".jpeg")
+        || name.to_lowercase().ends_with(".gif")
+        || name.to_lowercase().ends_with(".bmp")
+        || name.to_lowercase().ends_with(".webp");
+    let is_text = name.ends_with(".txt");
+
+    if !is_image && !is_text {
+        return None;
+    }
+
+    let file_metadata = fs::metadata(path).ok()?;
+
+    let modified = file_metadata
+        .modified()
+        .unwrap_or(std::time::UNIX_EPOCH)
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    let json_path = path.with_file_name(format!("{}.json", name));
+    let has_metadata = json_path.exists();
+
+    let capture_metadata = if has_metadata {
+        load_capture_metadata(&json_path).ok()
+    } else {
+        None
+    };
+
+    let capture_type = if is_image { "image".to_string() } else { "text".to_string() };
+
+    Some(CaptureFile {
+        name: name.to_string(),
+        path: path.to_string_lossy().to_string(),
+        modified,
+        size: file_metadata.len(),
+        capture_type,
+        has_metadata,
+        metadata: capture_metadata,
+    })
+}
+
 fn load_capture_metadata(json_path: &PathBuf) -> Result<CaptureMetadata, String> {
     let content = fs::read_to_string(json_path)
         .map_err(|e| format!("Failed to read metadata file: {}", e))?;
@@ -168,75 +428,41 @@ fn load_capture_metadata(json_path: &PathBuf) -> Result<CaptureMetadata, String>
 
 #[tauri::command]
 fn get_capture_metadata(filename: String) -> Result<CaptureMetadata, String> {
-    let home_dir = dirs::home_dir().ok_or("Could not find home directory")?;
-    let captures_path = home_dir.join("Library/Application Support/Grab/captures");
     let json_filename = format!("{}.json", filename);
-    let json_path = captures_path.join(&json_filename);
-    
-    if !json_path.exists() {
-        return Err("Metadata file not found".to_string());
-    }
-    
+    let json_path = paths::locate_capture(&json_filename)
+        .ok_or_else(|| "Metadata file not found".to_string())?;
+
     load_capture_metadata(&json_path)
 }
 
 #[tauri::command]
 fn get_text_content(filename: String) -> Result<String, String> {
-    let home_dir = dirs::home_dir().ok_or("Could not find home directory")?;
-    let captures_path = home_dir.join("Library/Application Support/Grab/captures");
-    let file_path = captures_path.join(&filename);
-    
-    if !file_path.exists() {
-        return Err("Text file not found".to_string());
-    }
-    
+    let file_path = paths::locate_capture(&filename)
+        .ok_or_else(|| "Text file not found".to_string())?;
+
     fs::read_to_string(&file_path)
         .map_err(|e| format!("Failed to read text file: {}", e))
 }
 
 #[tauri::command]
 fn get_image_content(filename: String) -> Result<String, String> {
-    println!("ðŸ–¼ï¸ get_image_content called with filename: {}", filename);
-    
-    // Use the configurable captures directory
-    let captures_dir_str = get_captures_dir()?;
-    let captures_path = PathBuf::from(captures_dir_str);
-    let file_path = captures_path.join(&filename);
-    
-    println!("ðŸ” Looking for image at: {}", file_path.display());
-    println!("ðŸ“‚ Captures directory: {}", captures_path.display());
-    
-    if !file_path.exists() {
-        println!("âŒ Image file not found at: {}", file_path.display());
-        return Err(format!("Image file not found: {}", file_path.display()));
-    }
-    
+    let file_path = paths::locate_capture(&filename)
+        .ok_or_else(|| format!("Image file not found: {}", filename))?;
+
     let image_data = fs::read(&file_path)
-        .map_err(|e| {
-            println!("âŒ Failed to read image file: {}", e);
-            format!("Failed to read image file: {}", e)
-        })?;
-    
-    println!("âœ… Successfully read {} bytes of image data", image_data.len());
-    
-    let base64_data = base64::engine::general_purpose::STANDARD.encode(&image_data);
-    println!("âœ… Generated base64 data with length: {}", base64_data.len());
-    
-    Ok(base64_data)
+        .map_err(|e| format!("Failed to read image file: {}", e))?;
+
+    Ok(base64::engine::general_purpose::STANDARD.encode(&image_data))
 }
 
 #[tauri::command]
-fn check_clipboard_event() -> Result<Option<serde_json::Value>, String> {
-    let app_support = dirs::home_dir()
-        .ok_or("Failed to get home directory")?
-        .join("Library/Application Support/Grab");
-    
-    let clipboard_event_file = app_support.join("clipboard_event.json");
-    
+pub(crate) fn check_clipboard_event() -> Result<Option<serde_json::Value>, String> {
+    let clipboard_event_file = paths::app_support_dir().join("clipboard_event.json");
+
     if !clipboard_event_file.exists() {
         return Ok(None);
     }
-    
+
     let content = fs::read_to_string(&clipboard_event_file)
         .map_err(|e| format!("Failed to read clipboard event file: {}", e))?;
     
@@ -249,66 +475,32 @@ fn check_clipboard_event() -> Result<Option<serde_json::Value>, String> {
     Ok(Some(clipboard_event))
 }
 
-fn get_default_captures_dir() -> Result<PathBuf, String> {
-    let home_dir = dirs::home_dir().ok_or("Failed to get home directory")?;
-    let captures_dir = home_dir
-        .join("Library")
-        .join("Application Support")
-        .join("Grab")
-        .join("captures");
-    
-    if !captures_dir.exists() {
-        fs::create_dir_all(&captures_dir)
-            .map_err(|e| format!("Failed to create captures directory: {}", e))?;
-    }
-    
-    Ok(captures_dir)
-}
-
-fn get_settings_file_path() -> Result<PathBuf, String> {
-    let home_dir = dirs::home_dir().ok_or("Failed to get home directory")?;
-    let settings_dir = home_dir
-        .join("Library")
-        .join("Application Support")
-        .join("Grab");
-    
-    if !settings_dir.exists() {
-        fs::create_dir_all(&settings_dir)
-            .map_err(|e| format!("Failed to create settings directory: {}", e))?;
-    }
-    
-    Ok(settings_dir.join("settings.json"))
-}
-
 fn get_app_settings_internal() -> Result<AppSettings, String> {
-    let settings_path = get_settings_file_path()?;
-    
+    let settings_path = paths::settings_file();
+
     if !settings_path.exists() {
-        // Create default settings
-        let default_folder = get_default_captures_dir()?
-            .to_string_lossy()
-            .to_string();
-        
+        let default_folder = paths::default_captures_dir().to_string_lossy().to_string();
+
         let default_settings = AppSettings {
-            capture_folder: default_folder.clone(),
+            capture_folders: vec![default_folder.clone()],
             default_capture_folder: default_folder,
         };
-        
+
         let settings_json = serde_json::to_string_pretty(&default_settings)
             .map_err(|e| format!("Failed to serialize default settings: {}", e))?;
-        
-        fs::write(&settings_path, settings_json)
+
+        fs::write(settings_path, settings_json)
             .map_err(|e| format!("Failed to write default settings: {}", e))?;
-        
+
         return Ok(default_settings);
     }
-    
-    let settings_content = fs::read_to_string(&settings_path)
+
+    let settings_content = fs::read_to_string(settings_path)
         .map_err(|e| format!("Failed to read settings file: {}", e))?;
-    
+
     let settings: AppSettings = serde_json::from_str(&settings_content)
         .map_err(|e| format!("Failed to parse settings: {}", e))?;
-    
+
     Ok(settings)
 }
 
@@ -318,56 +510,150 @@ fn get_app_settings() -> Result<AppSettings, String> {
 }
 
 #[tauri::command]
-fn save_app_settings(settings: AppSettings) -> Result<(), String> {
-    let settings_path = get_settings_file_path()?;
-    
-    // Validate that the capture folder exists or can be created
-    let capture_path = PathBuf::from(&settings.capture_folder);
-    if !capture_path.exists() {
-        fs::create_dir_all(&capture_path)
-            .map_err(|e| format!("Failed to create capture folder: {}", e))?;
+fn save_app_settings(app_handle: tauri::AppHandle, settings: AppSettings) -> Result<(), String> {
+    let settings_path = paths::settings_file();
+
+    // Validate that every capture folder exists or can be created
+    for folder in &settings.capture_folders {
+        let capture_path = PathBuf::from(folder);
+        if !capture_path.exists() {
+            fs::create_dir_all(&capture_path)
+                .map_err(|e| format!("Failed to create capture folder {}: {}", folder, e))?;
+        }
     }
-    
+
     let settings_json = serde_json::to_string_pretty(&settings)
         .map_err(|e| format!("Failed to serialize settings: {}", e))?;
-    
-    fs::write(&settings_path, settings_json)
+
+    fs::write(settings_path, settings_json)
         .map_err(|e| format!("Failed to write settings file: {}", e))?;
-    
+
+    // The configured capture folders may have changed, so tear down and
+    // re-establish the watch against all of them.
+    watcher::restart(app_handle, paths::capture_folders());
+
     Ok(())
 }
 
 #[tauri::command]
 fn copy_image_to_clipboard(filename: String) -> Result<(), String> {
-    // Use the configurable captures directory
-    let captures_dir_str = get_captures_dir()?;
-    let captures_path = PathBuf::from(captures_dir_str);
-    let file_path = captures_path.join(&filename);
-    
-    if !file_path.exists() {
-        return Err("Image file not found".to_string());
+    let file_path = paths::locate_capture(&filename)
+        .ok_or_else(|| "Image file not found".to_string())?;
+
+    clipboard::copy_image(&file_path)
+}
+
+/// Outcome of one file within a batch command. Batches never abort on the
+/// first failure; each item reports its own success/error so the frontend
+/// can show a partial result instead of rolling back the whole selection.
+#[derive(Debug, Serialize, Deserialize)]
+struct BatchResult {
+    filename: String,
+    ok: bool,
+    error: Option<String>,
+}
+
+impl BatchResult {
+    fn ok(filename: &str) -> Self {
+        BatchResult { filename: filename.to_string(), ok: true, error: None }
     }
-    
-    // On macOS, we can use the `osascript` command to copy image to clipboard
-    use std::process::Command;
-    
-    let output = Command::new("osascript")
-        .arg("-e")
-        .arg(format!(
-            "set the clipboard to (read file POSIX file \"{}\") as JPEG picture",
-            file_path.to_string_lossy()
-        ))
-        .output()
-        .map_err(|e| format!("Failed to execute osascript: {}", e))?;
-    
-    if !output.status.success() {
-        let error_msg = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("Failed to copy image to clipboard: {}", error_msg));
+
+    fn err(filename: &str, error: String) -> Self {
+        BatchResult { filename: filename.to_string(), ok: false, error: Some(error) }
     }
-    
+}
+
+#[tauri::command]
+fn delete_capture(filename: String) -> Result<(), String> {
+    let file_path = paths::locate_capture(&filename)
+        .ok_or_else(|| format!("Capture file not found: {}", filename))?;
+
+    fs::remove_file(&file_path)
+        .map_err(|e| format!("Failed to delete capture: {}", e))?;
+
+    // The sidecar metadata file and cached thumbnail aren't referenced once
+    // the capture is gone; nothing else cleans them up, so remove them here
+    // if present.
+    if let Some(parent) = file_path.parent() {
+        let json_path = parent.join(format!("{}.json", filename));
+        if json_path.exists() {
+            let _ = fs::remove_file(&json_path);
+        }
+    }
+
+    let thumb_path = thumbnails::thumbnail_path(&file_path);
+    if thumb_path.exists() {
+        let _ = fs::remove_file(&thumb_path);
+    }
+
     Ok(())
 }
 
+#[tauri::command]
+fn delete_captures(filenames: Vec<String>) -> Result<Vec<BatchResult>, String> {
+    Ok(filenames
+        .iter()
+        .map(|filename| match delete_capture(filename.clone()) {
+            Ok(()) => BatchResult::ok(filename),
+            Err(e) => BatchResult::err(filename, e),
+        })
+        .collect())
+}
+
+#[tauri::command]
+fn copy_images_to_clipboard(filenames: Vec<String>) -> Result<Vec<BatchResult>, String> {
+    Ok(filenames
+        .iter()
+        .map(|filename| match copy_image_to_clipboard(filename.clone()) {
+            Ok(()) => BatchResult::ok(filename),
+            Err(e) => BatchResult::err(filename, e),
+        })
+        .collect())
+}
+
+#[tauri::command]
+fn move_captures(filenames: Vec<String>, dest_folder: String) -> Result<Vec<BatchResult>, String> {
+    let dest_path = PathBuf::from(&dest_folder);
+
+    if !dest_path.exists() {
+        if let Err(e) = fs::create_dir_all(&dest_path) {
+            return Err(format!("Failed to create destination folder: {}", e));
+        }
+    }
+
+    Ok(filenames
+        .iter()
+        .map(|filename| move_single_capture(&dest_path, filename))
+        .collect())
+}
+
+fn move_single_capture(dest_path: &std::path::Path, filename: &str) -> BatchResult {
+    let src_file = match paths::locate_capture(filename) {
+        Some(path) => path,
+        None => return BatchResult::err(filename, format!("Capture file not found: {}", filename)),
+    };
+
+    let dest_file = dest_path.join(filename);
+    if let Err(e) = fs::rename(&src_file, &dest_file) {
+        return BatchResult::err(filename, format!("Failed to move capture: {}", e));
+    }
+
+    // Bring the sidecar metadata file along if it exists.
+    if let Some(parent) = src_file.parent() {
+        let src_json = parent.join(format!("{}.json", filename));
+        if src_json.exists() {
+            let dest_json = dest_path.join(format!("{}.json", filename));
+            let _ = fs::rename(&src_json, &dest_json);
+        }
+    }
+
+    // The cached thumbnail lives in a fixed cache directory keyed only by
+    // filename (see `thumbnails::thumbnail_path`), not alongside the
+    // capture, so it stays valid after the move with nothing to carry over.
+
+    BatchResult::ok(filename)
+}
+
 fn handle_capture_id(app_handle: tauri::AppHandle, capture_id: &str) {
     // Emit event to frontend with capture ID
     app_handle.emit_all("capture-id", capture_id).unwrap_or_else(|e| {
@@ -401,13 +687,23 @@ fn main() {
             get_app_settings,
             save_app_settings,
             copy_image_to_clipboard,
-            check_clipboard_event
+            check_clipboard_event,
+            open_with::list_openers,
+            open_with::open_capture_with,
+            delete_capture,
+            delete_captures,
+            copy_images_to_clipboard,
+            move_captures,
+            search_captures,
+            get_capture_thumbnail
         ])
+        .manage(watcher::WatcherState::new())
         .setup(|app| {
             // Handle capture ID from command line arguments on app startup
             if let Some(capture_id) = parse_command_line_args() {
                 handle_capture_id(app.handle(), &capture_id);
             }
+            watcher::start(app.handle());
             Ok(())
         })
         .on_window_event(|event| {
@@ -0,0 +1,247 @@
+//! "Open With" subsystem: discovering and launching external applications
+//! capable of opening a capture.
+//!
+//! Platform support is split into submodules so each OS can grow its own
+//! discovery strategy (LaunchServices on macOS, `.desktop` parsing on Linux,
+//! the registry on Windows) behind the same `list_openers`/`launch_with`/
+//! `launch_default` calls.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AppOpener {
+    /// Platform-specific identifier used to launch the app (e.g. a macOS
+    /// bundle identifier). Pass this back into `open_capture_with`. `None`
+    /// means "the OS's default handler" — `open_capture_with` routes that
+    /// to `launch_default` instead of `launch_with`.
+    pub identifier: Option<String>,
+    pub name: String,
+    pub is_default: bool,
+}
+
+#[tauri::command]
+pub fn list_openers(filename: String) -> Result<Vec<AppOpener>, String> {
+    let path = crate::paths::locate_capture(&filename)
+        .ok_or_else(|| format!("Capture file not found: {}", filename))?;
+
+    platform::list_openers(&path)
+}
+
+#[tauri::command]
+pub fn open_capture_with(filename: String, app_identifier: Option<String>) -> Result<(), String> {
+    let path = crate::paths::locate_capture(&filename)
+        .ok_or_else(|| format!("Capture file not found: {}", filename))?;
+
+    match app_identifier {
+        Some(identifier) => platform::launch_with(&path, &identifier),
+        None => platform::launch_default(&path),
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::path::Path;
+    use std::process::Command;
+
+    use super::AppOpener;
+
+    /// Enumerate candidate apps via LaunchServices. We shell out to
+    /// `lsregister -dump` rather than linking CoreServices directly so
+    /// this stays a plain `Command` call like the rest of the backend.
+    pub fn list_openers(path: &Path) -> Result<Vec<AppOpener>, String> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        let uti = extension_to_uti(&extension);
+
+        let lsregister = "/System/Library/Frameworks/CoreServices.framework/Frameworks/LaunchServices.framework/Support/lsregister";
+        let output = Command::new(lsregister)
+            .arg("-dump")
+            .output()
+            .map_err(|e| format!("Failed to query lsregister: {}", e))?;
+
+        let dump = String::from_utf8_lossy(&output.stdout);
+        let mut openers = parse_lsregister_dump(&dump, &uti);
+
+        if openers.is_empty() {
+            // No registrant claims the UTI; fall back to the OS's default
+            // handler. `identifier: None` routes `open_capture_with` to
+            // `launch_default` (`open <path>`) rather than `open -b <id>`
+            // with a bogus identifier.
+            openers.push(AppOpener {
+                identifier: None,
+                name: "Default Application".to_string(),
+                is_default: true,
+            });
+        }
+
+        Ok(openers)
+    }
+
+    pub fn launch_with(path: &Path, app_identifier: &str) -> Result<(), String> {
+        let output = Command::new("open")
+            .arg("-b")
+            .arg(app_identifier)
+            .arg(path)
+            .output()
+            .map_err(|e| format!("Failed to launch {}: {}", app_identifier, e))?;
+
+        if !output.status.success() {
+            let error_msg = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Failed to open with {}: {}", app_identifier, error_msg));
+        }
+
+        Ok(())
+    }
+
+    pub fn launch_default(path: &Path) -> Result<(), String> {
+        let output = Command::new("open")
+            .arg(path)
+            .output()
+            .map_err(|e| format!("Failed to open file: {}", e))?;
+
+        if !output.status.success() {
+            let error_msg = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Failed to open file: {}", error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// The concrete UTI an app would actually register a conformance for.
+    /// Apps claim specific types like `public.png`, not the umbrella
+    /// `public.image`, so matching against the umbrella type finds nothing
+    /// in the `lsregister` dump and always falls through to the
+    /// default-handler fallback.
+    fn extension_to_uti(extension: &str) -> String {
+        match extension {
+            "png" => "public.png",
+            "jpg" | "jpeg" => "public.jpeg",
+            "gif" => "com.compuserve.gif",
+            "bmp" => "com.microsoft.bmp",
+            "webp" => "org.webmproject.webp",
+            "txt" => "public.plain-text",
+            _ => "public.data",
+        }
+        .to_string()
+    }
+
+    /// `lsregister -dump` output is a loose, not-quite-structured text dump.
+    /// We only need the bundle identifiers that claim to handle the UTI, so
+    /// a line-scoped scan is enough; it avoids depending on a real plist/XML
+    /// parser for something this approximate. The UTI needle is only
+    /// checked against lines inside the current bundle's "claimed UTIs"
+    /// block, not every line in the dump, since bundle ids/paths can
+    /// otherwise contain a matching substring.
+    fn parse_lsregister_dump(dump: &str, uti: &str) -> Vec<AppOpener> {
+        let mut seen = std::collections::HashSet::new();
+        let mut openers = Vec::new();
+
+        let mut current_bundle_id: Option<String> = None;
+        let mut current_name: Option<String> = None;
+        let mut in_claims_block = false;
+        let mut claims_uti = false;
+
+        for line in dump.lines() {
+            let trimmed = line.trim();
+
+            if trimmed.starts_with("bundle id:") {
+                current_bundle_id = trimmed
+                    .split_once(':')
+                    .map(|(_, v)| v.trim().to_string());
+                current_name = None;
+                in_claims_block = false;
+                claims_uti = false;
+                continue;
+            }
+
+            if trimmed.starts_with("name:") && current_name.is_none() {
+                current_name = trimmed.split_once(':').map(|(_, v)| v.trim().to_string());
+                continue;
+            }
+
+            if trimmed.ends_with("claimed UTIs:") {
+                in_claims_block = true;
+                continue;
+            }
+
+            if in_claims_block {
+                if trimmed.is_empty() || trimmed.contains(':') {
+                    // Blank line or the next key/value pair ends the block.
+                    in_claims_block = false;
+                } else if trimmed.split_whitespace().any(|token| token == uti) {
+                    claims_uti = true;
+                }
+            }
+
+            if claims_uti {
+                if let (Some(id), Some(name)) = (&current_bundle_id, &current_name) {
+                    if seen.insert(id.clone()) {
+                        openers.push(AppOpener {
+                            identifier: Some(id.clone()),
+                            name: name.clone(),
+                            is_default: false,
+                        });
+                    }
+                }
+            }
+        }
+
+        openers
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::path::Path;
+
+    use super::AppOpener;
+
+    // TODO: parse `.desktop` files under /usr/share/applications and
+    // ~/.local/share/applications, matching the capture's MIME type against
+    // each entry's `MimeType=` key and resolving the launch command from
+    // `Exec=`.
+    pub fn list_openers(_path: &Path) -> Result<Vec<AppOpener>, String> {
+        Err("Open With is not yet implemented on Linux".to_string())
+    }
+
+    pub fn launch_with(_path: &Path, _app_identifier: &str) -> Result<(), String> {
+        Err("Open With is not yet implemented on Linux".to_string())
+    }
+
+    pub fn launch_default(path: &Path) -> Result<(), String> {
+        std::process::Command::new("xdg-open")
+            .arg(path)
+            .status()
+            .map_err(|e| format!("Failed to open file: {}", e))?;
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::path::Path;
+
+    use super::AppOpener;
+
+    // TODO: enumerate handlers from the registry under
+    // HKEY_CLASSES_ROOT\<ext>\OpenWithProgids / OpenWithList.
+    pub fn list_openers(_path: &Path) -> Result<Vec<AppOpener>, String> {
+        Err("Open With is not yet implemented on Windows".to_string())
+    }
+
+    pub fn launch_with(_path: &Path, _app_identifier: &str) -> Result<(), String> {
+        Err("Open With is not yet implemented on Windows".to_string())
+    }
+
+    pub fn launch_default(path: &Path) -> Result<(), String> {
+        std::process::Command::new("cmd")
+            .args(["/C", "start", "", &path.to_string_lossy()])
+            .status()
+            .map_err(|e| format!("Failed to open file: {}", e))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//! Centralized path resolution.
+//!
+//! Before this module existed, the macOS captures path was hardcoded in
+//! half a dozen places and a couple of commands used the configurable
+//! directory while the rest didn't, so they disagreed about where files
+//! actually live. Everything now resolves through here instead.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::AppSettings;
+
+static APP_SUPPORT_DIR: OnceLock<PathBuf> = OnceLock::new();
+static SETTINGS_FILE: OnceLock<PathBuf> = OnceLock::new();
+static DEFAULT_CAPTURES_DIR: OnceLock<PathBuf> = OnceLock::new();
+static THUMBNAIL_CACHE_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// The app's per-platform support directory, e.g.
+/// `~/Library/Application Support/Grab` on macOS or the `dirs::data_dir()`
+/// equivalent on Linux/Windows.
+pub(crate) fn app_support_dir() -> &'static PathBuf {
+    APP_SUPPORT_DIR.get_or_init(|| {
+        let dir = platform_app_support_dir();
+        let _ = std::fs::create_dir_all(&dir);
+        dir
+    })
+}
+
+pub(crate) fn settings_file() -> &'static PathBuf {
+    SETTINGS_FILE.get_or_init(|| app_support_dir().join("settings.json"))
+}
+
+/// The captures folder used when no custom folder has been configured, or
+/// the configured one no longer exists.
+pub(crate) fn default_captures_dir() -> &'static PathBuf {
+    DEFAULT_CAPTURES_DIR.get_or_init(|| {
+        let dir = app_support_dir().join("captures");
+        let _ = std::fs::create_dir_all(&dir);
+        dir
+    })
+}
+
+/// Where cached thumbnails live. Deliberately outside any captures folder
+/// so the cache files never get picked up as captures themselves.
+pub(crate) fn thumbnail_cache_dir() -> &'static PathBuf {
+    THUMBNAIL_CACHE_DIR.get_or_init(|| {
+        let dir = app_support_dir().join("thumbnails");
+        let _ = std::fs::create_dir_all(&dir);
+        dir
+    })
+}
+
+/// The primary captures folder: the first configured folder that still
+/// exists, or the default. Used by commands that only care about a single
+/// location; `capture_folders()` is the multi-folder equivalent used by
+/// `list_captures`/`search_captures` and the watcher.
+pub(crate) fn captures_dir() -> PathBuf {
+    capture_folders().into_iter().next().unwrap_or_else(|| default_captures_dir().clone())
+}
+
+/// All configured capture folders that currently exist on disk, falling
+/// back to the default if none are configured or none exist. This is read
+/// fresh each call rather than cached, since it can change at runtime via
+/// `save_app_settings`.
+pub(crate) fn capture_folders() -> Vec<PathBuf> {
+    let configured: Vec<PathBuf> = configured_capture_folders()
+        .into_iter()
+        .filter(|p| p.exists())
+        .collect();
+
+    if configured.is_empty() {
+        vec![default_captures_dir().clone()]
+    } else {
+        configured
+    }
+}
+
+/// Find which configured capture folder a given filename lives in.
+pub(crate) fn locate_capture(filename: &str) -> Option<PathBuf> {
+    capture_folders()
+        .into_iter()
+        .map(|dir| dir.join(filename))
+        .find(|path| path.exists())
+}
+
+fn configured_capture_folders() -> Vec<PathBuf> {
+    let content = match std::fs::read_to_string(settings_file()) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+    let settings: AppSettings = match serde_json::from_str(&content) {
+        Ok(s) => s,
+        Err(_) => return Vec::new(),
+    };
+    settings.capture_folders.into_iter().map(PathBuf::from).collect()
+}
+
+#[cfg(target_os = "macos")]
+fn platform_app_support_dir() -> PathBuf {
+    dirs::home_dir()
+        .expect("could not resolve home directory")
+        .join("Library")
+        .join("Application Support")
+        .join("Grab")
+}
+
+#[cfg(not(target_os = "macos"))]
+fn platform_app_support_dir() -> PathBuf {
+    dirs::data_dir()
+        .expect("could not resolve data directory")
+        .join("Grab")
+}
@@ -0,0 +1,50 @@
+//! Thumbnail caching and perceptual hashing for image captures.
+//!
+//! Downscaled copies are cached under the app support directory's
+//! `thumbnails/` subfolder, not inside the captures directory itself —
+//! `build_capture_file` classifies anything ending in an image extension as
+//! a capture, so a cached `.jpg` sitting next to the original would
+//! reappear in the gallery as a phantom capture.
+
+use std::path::{Path, PathBuf};
+
+use image::DynamicImage;
+
+pub(crate) const DEFAULT_MAX_PX: u32 = 320;
+
+pub(crate) fn thumbnail_path(capture_path: &Path) -> PathBuf {
+    let name = capture_path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+    crate::paths::thumbnail_cache_dir().join(format!("{}.thumb.jpg", name))
+}
+
+/// Write a cached thumbnail for `capture_path` if one doesn't already exist.
+pub(crate) fn ensure_cached(capture_path: &Path, img: &DynamicImage) -> Result<PathBuf, String> {
+    let thumb_path = thumbnail_path(capture_path);
+    if !thumb_path.exists() {
+        img.thumbnail(DEFAULT_MAX_PX, DEFAULT_MAX_PX)
+            .to_rgb8()
+            .save_with_format(&thumb_path, image::ImageFormat::Jpeg)
+            .map_err(|e| format!("Failed to write thumbnail: {}", e))?;
+    }
+    Ok(thumb_path)
+}
+
+/// Cheap perceptual hash for duplicate detection: the classic "average
+/// hash" (aHash) — downscale to 8x8 grayscale, threshold against the mean,
+/// pack the bits into a hex string.
+pub(crate) fn average_hash(img: &DynamicImage) -> String {
+    let small = img
+        .resize_exact(8, 8, image::imageops::FilterType::Triangle)
+        .to_luma8();
+    let pixels: Vec<u8> = small.pixels().map(|p| p.0[0]).collect();
+    let avg = pixels.iter().map(|&p| p as u32).sum::<u32>() / pixels.len() as u32;
+
+    let mut hash: u64 = 0;
+    for (i, &p) in pixels.iter().enumerate() {
+        if p as u32 >= avg {
+            hash |= 1 << i;
+        }
+    }
+
+    format!("{:016x}", hash)
+}
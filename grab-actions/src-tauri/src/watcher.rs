@@ -0,0 +1,141 @@
+//! Filesystem watcher that replaces the old polling `check_clipboard_event`
+//! drain. Watches the captures directory (and `clipboard_event.json`) and
+//! pushes `capture-added` / `capture-removed` / `capture-changed` /
+//! `clipboard-event` Tauri events so the gallery can update reactively
+//! instead of refreshing on a timer.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use notify_debouncer_mini::{new_debouncer, DebouncedEvent, Debouncer};
+use tauri::{AppHandle, Manager};
+
+use crate::{build_capture_file, check_clipboard_event, paths};
+
+/// Screenshots are often written as a temp file then renamed into place, so
+/// a single capture can fire several raw fs events in quick succession; this
+/// is the window the debouncer coalesces them within.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(400);
+
+pub struct WatcherState(Mutex<Inner>);
+
+struct Inner {
+    debouncer: Option<Debouncer<notify::RecommendedWatcher>>,
+    known_files: HashSet<String>,
+}
+
+impl WatcherState {
+    pub fn new() -> Self {
+        WatcherState(Mutex::new(Inner { debouncer: None, known_files: HashSet::new() }))
+    }
+}
+
+/// Begin watching every configured captures folder. Called once from
+/// `setup`.
+pub fn start(app_handle: AppHandle) {
+    watch(app_handle, paths::capture_folders());
+}
+
+/// Tear down the existing watch and re-establish it against the
+/// now-configured capture folders. Called after `save_app_settings` changes
+/// them.
+pub fn restart(app_handle: AppHandle, captures_dirs: Vec<PathBuf>) {
+    watch(app_handle, captures_dirs);
+}
+
+fn watch(app_handle: AppHandle, captures_dirs: Vec<PathBuf>) {
+    let known_files = initial_known_files(&captures_dirs);
+
+    let handle = app_handle.clone();
+    let debouncer = new_debouncer(DEBOUNCE_WINDOW, move |result| match result {
+        Ok(events) => handle_events(&handle, events),
+        Err(e) => eprintln!("Watcher error: {:?}", e),
+    });
+
+    let mut debouncer = match debouncer {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("Watcher: failed to create debouncer: {}", e);
+            return;
+        }
+    };
+
+    for captures_dir in &captures_dirs {
+        if let Err(e) = debouncer
+            .watcher()
+            .watch(captures_dir, notify::RecursiveMode::NonRecursive)
+        {
+            eprintln!("Watcher: failed to watch {}: {}", captures_dir.display(), e);
+        }
+    }
+
+    // `clipboard_event.json` always lives in the app support directory,
+    // regardless of which folders captures themselves are saved to.
+    let app_support_dir = paths::app_support_dir();
+    if let Err(e) = debouncer
+        .watcher()
+        .watch(app_support_dir, notify::RecursiveMode::NonRecursive)
+    {
+        eprintln!("Watcher: failed to watch {}: {}", app_support_dir.display(), e);
+    }
+
+    let state = app_handle.state::<WatcherState>();
+    let mut inner = state.0.lock().unwrap();
+    inner.debouncer = Some(debouncer);
+    inner.known_files = known_files;
+}
+
+fn initial_known_files(captures_dirs: &[PathBuf]) -> HashSet<String> {
+    let mut known = HashSet::new();
+    for captures_dir in captures_dirs {
+        if let Ok(entries) = std::fs::read_dir(captures_dir) {
+            for entry in entries.flatten() {
+                if let Some(name) = entry.path().file_name().and_then(|n| n.to_str()) {
+                    known.insert(name.to_string());
+                }
+            }
+        }
+    }
+    known
+}
+
+fn handle_events(app_handle: &AppHandle, events: Vec<DebouncedEvent>) {
+    let state = app_handle.state::<WatcherState>();
+
+    for event in events {
+        let path = event.path;
+        let Some(name) = path.file_name().and_then(|n| n.to_str()).map(str::to_string) else {
+            continue;
+        };
+
+        if name == "clipboard_event.json" {
+            if let Ok(Some(value)) = check_clipboard_event() {
+                let _ = app_handle.emit_all("clipboard-event", value);
+            }
+            continue;
+        }
+
+        let mut inner = state.0.lock().unwrap();
+
+        if !path.exists() {
+            if inner.known_files.remove(&name) {
+                let _ = app_handle.emit_all("capture-removed", name);
+            }
+            continue;
+        }
+
+        let Some(capture) = build_capture_file(&path) else {
+            continue;
+        };
+
+        let event_name = if inner.known_files.insert(name.clone()) {
+            "capture-added"
+        } else {
+            "capture-changed"
+        };
+
+        let _ = app_handle.emit_all(event_name, capture);
+    }
+}